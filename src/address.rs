@@ -0,0 +1,224 @@
+use crate::config::mode1;
+use crate::device::reg;
+use embedded_hal::blocking::i2c::Write;
+use crate::types::{Channel, Error, Pca9685};
+
+/// The three programmable I²C sub-addresses (SUBADR1-3), in addition to the
+/// device's own hardware address.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SubAddress {
+    /// SUBADR1
+    Sub1,
+    /// SUBADR2
+    Sub2,
+    /// SUBADR3
+    Sub3,
+}
+
+impl SubAddress {
+    fn register(self) -> u8 {
+        match self {
+            SubAddress::Sub1 => reg::SUBADR1,
+            SubAddress::Sub2 => reg::SUBADR2,
+            SubAddress::Sub3 => reg::SUBADR3,
+        }
+    }
+
+    fn mode1_bit(self) -> u8 {
+        match self {
+            SubAddress::Sub1 => mode1::SUB1,
+            SubAddress::Sub2 => mode1::SUB2,
+            SubAddress::Sub3 => mode1::SUB3,
+        }
+    }
+}
+
+impl<I2C, E> Pca9685<I2C>
+where
+    I2C: Write<Error = E>,
+{
+    /// Makes the device respond to writes on its ALLCALL address, so that a
+    /// single write can update every device in a daisy-chain at once.
+    ///
+    /// Enabled by default out of reset.
+    pub fn enable_all_call(&mut self) -> Result<(), Error<E>> {
+        let config = self.inner.borrow().config.with_high(mode1::ALL_CALL);
+        self.write_register(reg::MODE1, config.bits)?;
+        self.inner.borrow_mut().config = config;
+        Ok(())
+    }
+
+    /// Stops the device from responding to writes on its ALLCALL address.
+    pub fn disable_all_call(&mut self) -> Result<(), Error<E>> {
+        let config = self.inner.borrow().config.with_low(mode1::ALL_CALL);
+        self.write_register(reg::MODE1, config.bits)?;
+        self.inner.borrow_mut().config = config;
+        Ok(())
+    }
+
+    /// Sets the 7-bit I²C address the device listens on for ALLCALL writes.
+    pub fn set_all_call_address(&mut self, address: u8) -> Result<(), Error<E>> {
+        self.write_register(reg::ALLCALLADR, address << 1)
+    }
+
+    /// Sets the 7-bit I²C address associated with the given sub-address.
+    ///
+    /// Note this does not by itself make the device respond on it; see
+    /// `enable_sub_address()`.
+    pub fn set_sub_address(&mut self, sub_address: SubAddress, address: u8) -> Result<(), Error<E>> {
+        self.write_register(sub_address.register(), address << 1)
+    }
+
+    /// Makes the device respond to writes on the given sub-address, so that
+    /// a group of devices sharing that address can be updated atomically.
+    pub fn enable_sub_address(&mut self, sub_address: SubAddress) -> Result<(), Error<E>> {
+        let config = self.inner.borrow().config.with_high(sub_address.mode1_bit());
+        self.write_register(reg::MODE1, config.bits)?;
+        self.inner.borrow_mut().config = config;
+        Ok(())
+    }
+
+    /// Stops the device from responding to writes on the given sub-address.
+    pub fn disable_sub_address(&mut self, sub_address: SubAddress) -> Result<(), Error<E>> {
+        let config = self.inner.borrow().config.with_low(sub_address.mode1_bit());
+        self.write_register(reg::MODE1, config.bits)?;
+        self.inner.borrow_mut().config = config;
+        Ok(())
+    }
+
+    /// Sets a channel's ON and OFF counts (12-bit values, `0..=4095`) on
+    /// every device listening on `address`, e.g. the configured ALLCALL or a
+    /// sub-address, issuing a single I²C write instead of looping per
+    /// device.
+    pub fn set_channel_on_off_at_address(
+        &mut self,
+        address: u8,
+        channel: Channel,
+        on: u16,
+        off: u16,
+    ) -> Result<(), Error<E>> {
+        if on > 4095 || off > 4095 {
+            return Err(Error::InvalidInputData);
+        }
+        let register = match channel.register_offset() {
+            Some(offset) => reg::LED0_ON_L + 4 * offset,
+            None => reg::ALL_LED_ON_L,
+        };
+        self.write_double_register_to_address(address, register, on)?;
+        self.write_double_register_to_address(address, register + 2, off)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A minimal `Write` mock recording up to 4 writes, avoiding any need
+    /// for an allocator under `no_std`.
+    #[derive(Default)]
+    struct RecordingI2c {
+        writes: [(u8, [u8; 3]); 4],
+        count: usize,
+    }
+
+    impl Write for RecordingI2c {
+        type Error = ();
+
+        fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), ()> {
+            let mut buf = [0u8; 3];
+            buf[..bytes.len()].copy_from_slice(bytes);
+            self.writes[self.count] = (address, buf);
+            self.count += 1;
+            Ok(())
+        }
+    }
+
+    fn device() -> Pca9685<RecordingI2c> {
+        Pca9685::<RecordingI2c>::default()
+    }
+
+    #[test]
+    fn enable_all_call_sets_the_mode1_bit_without_disturbing_others() {
+        let mut dev = device();
+        let address = dev.inner.borrow().address;
+        let before = dev.inner.borrow().config.bits;
+
+        dev.enable_all_call().unwrap();
+
+        assert_eq!(
+            dev.inner.borrow().i2c.writes[0],
+            (address, [reg::MODE1, before | mode1::ALL_CALL, 0])
+        );
+        assert_eq!(dev.inner.borrow().config.bits, before | mode1::ALL_CALL);
+    }
+
+    #[test]
+    fn disable_all_call_clears_only_the_all_call_bit() {
+        let mut dev = device();
+        dev.enable_all_call().unwrap();
+        let enabled = dev.inner.borrow().config.bits;
+
+        dev.disable_all_call().unwrap();
+
+        assert_eq!(dev.inner.borrow().config.bits, enabled & !mode1::ALL_CALL);
+    }
+
+    #[test]
+    fn set_all_call_address_shifts_into_the_7_bit_field() {
+        let mut dev = device();
+        dev.set_all_call_address(0x55).unwrap();
+        assert_eq!(
+            dev.inner.borrow().i2c.writes[0].1,
+            [reg::ALLCALLADR, 0x55 << 1, 0]
+        );
+    }
+
+    #[test]
+    fn set_sub_address_writes_the_right_register_per_variant() {
+        let mut dev = device();
+        dev.set_sub_address(SubAddress::Sub2, 0x2A).unwrap();
+        assert_eq!(
+            dev.inner.borrow().i2c.writes[0].1,
+            [reg::SUBADR2, 0x2A << 1, 0]
+        );
+    }
+
+    #[test]
+    fn enable_sub_address_preserves_previously_enabled_bits() {
+        let mut dev = device();
+        dev.enable_all_call().unwrap();
+        dev.enable_sub_address(SubAddress::Sub1).unwrap();
+
+        let bits = dev.inner.borrow().config.bits;
+        assert_eq!(bits & mode1::ALL_CALL, mode1::ALL_CALL);
+        assert_eq!(bits & mode1::SUB1, mode1::SUB1);
+
+        dev.disable_sub_address(SubAddress::Sub1).unwrap();
+        let bits = dev.inner.borrow().config.bits;
+        assert_eq!(bits & mode1::SUB1, 0);
+        assert_eq!(bits & mode1::ALL_CALL, mode1::ALL_CALL);
+    }
+
+    #[test]
+    fn set_channel_on_off_at_address_targets_the_given_address_not_self() {
+        let mut dev = device();
+        let own_address = dev.inner.borrow().address;
+        let group_address = own_address + 1;
+
+        dev.set_channel_on_off_at_address(group_address, Channel::C0, 0, 100)
+            .unwrap();
+
+        let writes = dev.inner.borrow().i2c.writes;
+        assert_eq!(writes[0], (group_address, [6, 0, 0]));
+        assert_eq!(writes[1], (group_address, [8, 100, 0]));
+    }
+
+    #[test]
+    fn set_channel_on_off_at_address_rejects_out_of_range_counts() {
+        let mut dev = device();
+        assert!(matches!(
+            dev.set_channel_on_off_at_address(0x70, Channel::C0, 0, 4096),
+            Err(Error::InvalidInputData)
+        ));
+    }
+}
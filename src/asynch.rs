@@ -0,0 +1,108 @@
+//! Async variant of the device methods, built on `embedded-hal-async`.
+//!
+//! Mirrors the blocking method set in `device.rs`, sharing the same
+//! register addresses and `Config`/MODE1 bit encoding, so that PWM updates
+//! can interleave with other bus traffic on RTOS/Embassy targets instead of
+//! busy-blocking. Enabled with the `async` feature.
+//!
+//! Requires the 2018 edition or later for `async fn`/`.await`; every module
+//! path in this crate is written `crate::`-relative rather than bare so that
+//! it resolves the same way under 2018+ as it did under 2015.
+
+use crate::config::mode1;
+use crate::device::{reg, FULL_ON_OFF_BIT};
+use embedded_hal_async::i2c::I2c;
+use crate::types::{Channel, Error, Pca9685};
+
+impl<I2C, E> Pca9685<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    async fn write_register_async(&mut self, register: u8, value: u8) -> Result<(), Error<E>> {
+        let address = self.inner.borrow().address;
+        self.inner
+            .borrow_mut()
+            .i2c
+            .write(address, &[register, value])
+            .await
+            .map_err(Error::I2C)
+    }
+
+    async fn write_double_register_async(
+        &mut self,
+        register: u8,
+        value: u16,
+    ) -> Result<(), Error<E>> {
+        let address = self.inner.borrow().address;
+        self.inner
+            .borrow_mut()
+            .i2c
+            .write(address, &[register, value as u8, (value >> 8) as u8])
+            .await
+            .map_err(Error::I2C)
+    }
+
+    /// Async equivalent of `enable()`.
+    pub async fn enable_async(&mut self) -> Result<(), Error<E>> {
+        let config = self.inner.borrow().config.with_low(mode1::SLEEP);
+        self.write_register_async(reg::MODE1, config.bits).await?;
+        self.inner.borrow_mut().config = config;
+        Ok(())
+    }
+
+    /// Async equivalent of `disable()`.
+    pub async fn disable_async(&mut self) -> Result<(), Error<E>> {
+        let config = self.inner.borrow().config.with_high(mode1::SLEEP);
+        self.write_register_async(reg::MODE1, config.bits).await?;
+        self.inner.borrow_mut().config = config;
+        Ok(())
+    }
+
+    /// Async equivalent of `set_prescale()`.
+    pub async fn set_prescale_async(&mut self, prescale: u8) -> Result<(), Error<E>> {
+        if !self.inner.borrow().config.is_high(mode1::SLEEP) {
+            return Err(Error::InvalidInputData);
+        }
+        self.write_register_async(reg::PRE_SCALE, prescale).await?;
+        self.inner.borrow_mut().prescale = prescale;
+        Ok(())
+    }
+
+    /// Async equivalent of `set_channel_on_off()`.
+    pub async fn set_channel_on_off_async(
+        &mut self,
+        channel: Channel,
+        on: u16,
+        off: u16,
+    ) -> Result<(), Error<E>> {
+        if on > 4095 || off > 4095 {
+            return Err(Error::InvalidInputData);
+        }
+        let register = self.on_off_registers(channel);
+        self.write_double_register_async(register, on).await?;
+        self.write_double_register_async(register + 2, off).await
+    }
+
+    /// Async equivalent of `set_channel_full_on()`.
+    pub async fn set_channel_full_on_async(
+        &mut self,
+        channel: Channel,
+        value: u16,
+    ) -> Result<(), Error<E>> {
+        if value > 4095 {
+            return Err(Error::InvalidInputData);
+        }
+        let register = self.on_off_registers(channel);
+        self.write_double_register_async(register, value | u16::from(FULL_ON_OFF_BIT) << 8)
+            .await?;
+        self.write_double_register_async(register + 2, 0).await
+    }
+
+    /// Async equivalent of `set_channel_full_off()`.
+    pub async fn set_channel_full_off_async(&mut self, channel: Channel) -> Result<(), Error<E>> {
+        let register = self.on_off_registers(channel);
+        self.write_double_register_async(register, 0).await?;
+        self.write_double_register_async(register + 2, u16::from(FULL_ON_OFF_BIT) << 8)
+            .await
+    }
+}
@@ -0,0 +1,210 @@
+use crate::config::{mode1, Config};
+use crate::device::reg;
+use embedded_hal::blocking::i2c::Write;
+use crate::types::{Channel, Error};
+
+/// A chain of `N` PCA9685 devices sharing a single I²C bus, addressed
+/// through a flat logical-output index space instead of per-device
+/// `(address, Channel)` pairs.
+///
+/// Logical index `i` maps to device `i / 16` at `addresses[i / 16]`,
+/// channel `i % 16`.
+pub struct Pca9685Array<I2C, const N: usize> {
+    i2c: I2C,
+    addresses: [u8; N],
+    prescale: u8,
+    /// Last-known MODE1 bits per device, the same way `Pca9685` tracks its
+    /// own `Config`, so that bits unrelated to this chain's own operations
+    /// (e.g. ALLCALL/sub-address bits set through some other path) are
+    /// preserved rather than clobbered.
+    configs: [Config; N],
+}
+
+impl<I2C, E, const N: usize> Pca9685Array<I2C, N>
+where
+    I2C: Write<Error = E>,
+{
+    /// Creates a new chain driving the devices at `addresses` over the
+    /// shared bus `i2c`.
+    pub fn new(i2c: I2C, addresses: [u8; N]) -> Self {
+        Pca9685Array {
+            i2c,
+            addresses,
+            prescale: 0x1E,
+            configs: [Config::default(); N],
+        }
+    }
+
+    fn write_register(&mut self, address: u8, register: u8, value: u8) -> Result<(), Error<E>> {
+        self.i2c.write(address, &[register, value]).map_err(Error::I2C)
+    }
+
+    fn write_double_register(
+        &mut self,
+        address: u8,
+        register: u8,
+        value: u16,
+    ) -> Result<(), Error<E>> {
+        self.i2c
+            .write(address, &[register, value as u8, (value >> 8) as u8])
+            .map_err(Error::I2C)
+    }
+
+    /// Resolves a logical output index into the `(address, Channel)` pair it
+    /// routes to.
+    ///
+    /// Returns `Error::InvalidInputData` if `index` is out of range for
+    /// this chain (`index >= 16 * N`).
+    fn resolve(&self, index: usize) -> Result<(u8, Channel), Error<E>> {
+        if index >= 16 * N {
+            return Err(Error::InvalidInputData);
+        }
+        let device = index / 16;
+        let channel = match index % 16 {
+            0 => Channel::C0,
+            1 => Channel::C1,
+            2 => Channel::C2,
+            3 => Channel::C3,
+            4 => Channel::C4,
+            5 => Channel::C5,
+            6 => Channel::C6,
+            7 => Channel::C7,
+            8 => Channel::C8,
+            9 => Channel::C9,
+            10 => Channel::C10,
+            11 => Channel::C11,
+            12 => Channel::C12,
+            13 => Channel::C13,
+            14 => Channel::C14,
+            _ => Channel::C15,
+        };
+        Ok((self.addresses[device], channel))
+    }
+
+    /// Sets the ON and OFF counts (12-bit values, `0..=4095`) of the output
+    /// at logical index `index`, routing the write to the right device.
+    pub fn set_logical(&mut self, index: usize, on: u16, off: u16) -> Result<(), Error<E>> {
+        if on > 4095 || off > 4095 {
+            return Err(Error::InvalidInputData);
+        }
+        let (address, channel) = self.resolve(index)?;
+        let offset = channel
+            .register_offset()
+            .expect("resolve() never produces Channel::All");
+        let register = reg::LED0_ON_L + 4 * offset;
+        self.write_double_register(address, register, on)?;
+        self.write_double_register(address, register + 2, off)
+    }
+
+    /// Sets the ON and OFF counts for every output on every device in the
+    /// chain with two writes per device (ON, then OFF), using each device's `ALL_LED`
+    /// registers rather than looping over all `16 * N` logical outputs.
+    pub fn set_all_logical(&mut self, on: u16, off: u16) -> Result<(), Error<E>> {
+        if on > 4095 || off > 4095 {
+            return Err(Error::InvalidInputData);
+        }
+        for i in 0..N {
+            let address = self.addresses[i];
+            self.write_double_register(address, reg::ALL_LED_ON_L, on)?;
+            self.write_double_register(address, reg::ALL_LED_ON_L + 2, off)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the PRE_SCALE value `prescale`, which sets the PWM update
+    /// rate, to every device in the chain.
+    ///
+    /// Follows the usual sleep/set-prescale/wake sequence on each device in
+    /// turn, since the PRE_SCALE register can only be written while asleep.
+    /// Each device's other MODE1 bits (e.g. ALLCALL/sub-address enables) are
+    /// preserved rather than overwritten.
+    pub fn set_frequency_prescale(&mut self, prescale: u8) -> Result<(), Error<E>> {
+        for i in 0..N {
+            let address = self.addresses[i];
+            let asleep = self.configs[i].with_high(mode1::SLEEP);
+            self.write_register(address, reg::MODE1, asleep.bits)?;
+            self.write_register(address, reg::PRE_SCALE, prescale)?;
+            let awake = self.configs[i].with_low(mode1::SLEEP);
+            self.write_register(address, reg::MODE1, awake.bits)?;
+            self.configs[i] = awake;
+        }
+        self.prescale = prescale;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A minimal `Write` mock recording up to 8 writes, avoiding any need
+    /// for an allocator under `no_std`.
+    #[derive(Default)]
+    struct RecordingI2c {
+        writes: [(u8, [u8; 3]); 8],
+        count: usize,
+    }
+
+    impl Write for RecordingI2c {
+        type Error = ();
+
+        fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), ()> {
+            let mut buf = [0u8; 3];
+            buf[..bytes.len()].copy_from_slice(bytes);
+            self.writes[self.count] = (address, buf);
+            self.count += 1;
+            Ok(())
+        }
+    }
+
+    fn chain() -> Pca9685Array<RecordingI2c, 2> {
+        Pca9685Array::new(RecordingI2c::default(), [0x40, 0x41])
+    }
+
+    #[test]
+    fn set_logical_routes_to_the_right_device_and_channel() {
+        let mut dev = chain();
+        // Index 16 is the first channel (C0) of the second device (0x41).
+        dev.set_logical(16, 0, 123).unwrap();
+        assert_eq!(dev.i2c.writes[0], (0x41, [6, 0, 0]));
+        assert_eq!(dev.i2c.writes[1], (0x41, [8, 123, 0]));
+    }
+
+    #[test]
+    fn set_logical_rejects_out_of_range_index() {
+        let mut dev = chain();
+        assert!(matches!(
+            dev.set_logical(32, 0, 0),
+            Err(Error::InvalidInputData)
+        ));
+    }
+
+    #[test]
+    fn set_logical_rejects_out_of_range_counts() {
+        let mut dev = chain();
+        assert!(matches!(
+            dev.set_logical(0, 0, 4096),
+            Err(Error::InvalidInputData)
+        ));
+    }
+
+    #[test]
+    fn set_frequency_prescale_preserves_other_mode1_bits() {
+        let mut dev = chain();
+        // Simulate a device that already has e.g. ALLCALL enabled through
+        // some other path.
+        dev.configs[0] = dev.configs[0].with_high(mode1::ALL_CALL);
+        let expected_awake = dev.configs[0].with_low(mode1::SLEEP).bits;
+
+        dev.set_frequency_prescale(121).unwrap();
+
+        // Writes per device: [asleep, PRE_SCALE, awake]; device 0 first.
+        assert_eq!(
+            dev.i2c.writes[0],
+            (0x40, [reg::MODE1, expected_awake | mode1::SLEEP, 0])
+        );
+        assert_eq!(dev.i2c.writes[1], (0x40, [reg::PRE_SCALE, 121, 0]));
+        assert_eq!(dev.i2c.writes[2], (0x40, [reg::MODE1, expected_awake, 0]));
+        assert_eq!(dev.configs[0].bits, expected_awake);
+    }
+}
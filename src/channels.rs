@@ -0,0 +1,167 @@
+use embedded_hal::blocking::i2c::Write;
+use crate::types::{Channel, Error, Pca9685};
+
+/// The 16 individual channel handles produced by `split()`.
+pub struct Parts<I2C> {
+    /// Channel 0
+    pub c0: PwmChannel<I2C>,
+    /// Channel 1
+    pub c1: PwmChannel<I2C>,
+    /// Channel 2
+    pub c2: PwmChannel<I2C>,
+    /// Channel 3
+    pub c3: PwmChannel<I2C>,
+    /// Channel 4
+    pub c4: PwmChannel<I2C>,
+    /// Channel 5
+    pub c5: PwmChannel<I2C>,
+    /// Channel 6
+    pub c6: PwmChannel<I2C>,
+    /// Channel 7
+    pub c7: PwmChannel<I2C>,
+    /// Channel 8
+    pub c8: PwmChannel<I2C>,
+    /// Channel 9
+    pub c9: PwmChannel<I2C>,
+    /// Channel 10
+    pub c10: PwmChannel<I2C>,
+    /// Channel 11
+    pub c11: PwmChannel<I2C>,
+    /// Channel 12
+    pub c12: PwmChannel<I2C>,
+    /// Channel 13
+    pub c13: PwmChannel<I2C>,
+    /// Channel 14
+    pub c14: PwmChannel<I2C>,
+    /// Channel 15
+    pub c15: PwmChannel<I2C>,
+}
+
+impl<I2C> Pca9685<I2C> {
+    /// Splits the device into 16 independent channel handles, one per
+    /// `Channel::C0..C15`.
+    ///
+    /// Every handle is a `Pca9685` clone (sharing the same underlying
+    /// `Rc<RefCell<_>>` device state) paired with its own channel, so each
+    /// can be passed to a subsystem as a self-contained object without the
+    /// caller tracking channel indices.
+    pub fn split(&mut self) -> Parts<I2C> {
+        Parts {
+            c0: PwmChannel::new(self, Channel::C0),
+            c1: PwmChannel::new(self, Channel::C1),
+            c2: PwmChannel::new(self, Channel::C2),
+            c3: PwmChannel::new(self, Channel::C3),
+            c4: PwmChannel::new(self, Channel::C4),
+            c5: PwmChannel::new(self, Channel::C5),
+            c6: PwmChannel::new(self, Channel::C6),
+            c7: PwmChannel::new(self, Channel::C7),
+            c8: PwmChannel::new(self, Channel::C8),
+            c9: PwmChannel::new(self, Channel::C9),
+            c10: PwmChannel::new(self, Channel::C10),
+            c11: PwmChannel::new(self, Channel::C11),
+            c12: PwmChannel::new(self, Channel::C12),
+            c13: PwmChannel::new(self, Channel::C13),
+            c14: PwmChannel::new(self, Channel::C14),
+            c15: PwmChannel::new(self, Channel::C15),
+        }
+    }
+}
+
+/// A handle to a single PCA9685 output channel.
+///
+/// Internally just a `Pca9685` clone (sharing the device's `Rc<RefCell<_>>`
+/// state) plus a `Channel`, so it can be passed around as a self-contained
+/// object without the caller tracking channel indices.
+pub struct PwmChannel<I2C> {
+    device: Pca9685<I2C>,
+    channel: Channel,
+}
+
+impl<I2C> PwmChannel<I2C> {
+    fn new(device: &Pca9685<I2C>, channel: Channel) -> Self {
+        PwmChannel {
+            device: device.clone(),
+            channel,
+        }
+    }
+}
+
+impl<I2C, E> PwmChannel<I2C>
+where
+    I2C: Write<Error = E>,
+{
+    /// Sets this channel's ON and OFF counts (12-bit values, `0..=4095`).
+    pub fn set_on_off(&mut self, on: u16, off: u16) -> Result<(), Error<E>> {
+        self.device.set_channel_on_off(self.channel, on, off)
+    }
+
+    /// Sets this channel's duty cycle as a 12-bit count (`ON = 0`,
+    /// `OFF = duty`).
+    pub fn set_duty_cycle(&mut self, duty: u16) -> Result<(), Error<E>> {
+        self.set_on_off(0, duty)
+    }
+
+    /// Sets this channel to be always on.
+    pub fn set_full_on(&mut self) -> Result<(), Error<E>> {
+        self.device.set_channel_full_on(self.channel, 0)
+    }
+
+    /// Sets this channel to be always off.
+    pub fn set_full_off(&mut self) -> Result<(), Error<E>> {
+        self.device.set_channel_full_off(self.channel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A minimal `Write` mock recording up to 4 writes, avoiding any need
+    /// for an allocator under `no_std`.
+    #[derive(Default)]
+    struct RecordingI2c {
+        writes: [(u8, [u8; 3]); 4],
+        count: usize,
+    }
+
+    impl Write for RecordingI2c {
+        type Error = ();
+
+        fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), ()> {
+            let mut buf = [0u8; 3];
+            buf[..bytes.len()].copy_from_slice(bytes);
+            self.writes[self.count] = (address, buf);
+            self.count += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn split_channels_target_their_own_registers_independently() {
+        let mut device = Pca9685::<RecordingI2c>::default();
+        let mut parts = device.split();
+
+        parts.c0.set_duty_cycle(100).unwrap();
+        parts.c1.set_duty_cycle(200).unwrap();
+
+        // The device still routes writes through the one shared `RefCell`,
+        // so it observes every channel's writes, in order.
+        let inner = device.inner.borrow();
+        assert_eq!(inner.i2c.count, 4);
+        assert_eq!(inner.i2c.writes[0], (inner.address, [6, 0, 0]));
+        assert_eq!(inner.i2c.writes[1], (inner.address, [8, 100, 0]));
+        assert_eq!(inner.i2c.writes[2], (inner.address, [10, 0, 0]));
+        assert_eq!(inner.i2c.writes[3], (inner.address, [12, 200, 0]));
+    }
+
+    #[test]
+    fn split_channel_is_independently_owned() {
+        let mut device = Pca9685::<RecordingI2c>::default();
+        let parts = device.split();
+        // Each handle can be moved out and used on its own, without the
+        // caller tracking channel indices or holding onto `device`.
+        let mut c5 = parts.c5;
+        drop(device);
+        c5.set_full_on().unwrap();
+    }
+}
@@ -0,0 +1,52 @@
+//! MODE1/MODE2 register bit flags.
+
+/// MODE1 register bits.
+pub(crate) mod mode1 {
+    pub(crate) const AUTO_INCREMENT: u8 = 0b0010_0000;
+    pub(crate) const SLEEP: u8 = 0b0001_0000;
+    pub(crate) const SUB1: u8 = 0b0000_1000;
+    pub(crate) const SUB2: u8 = 0b0000_0100;
+    pub(crate) const SUB3: u8 = 0b0000_0010;
+    pub(crate) const ALL_CALL: u8 = 0b0000_0001;
+}
+
+/// MODE2 register bits.
+pub(crate) mod mode2 {
+    pub(crate) const INVRT: u8 = 0b0001_0000;
+    pub(crate) const OUTDRV: u8 = 0b0000_0100;
+}
+
+/// Device configuration held as the raw MODE1 register bits, so toggling a
+/// single bit never disturbs the others.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    pub(crate) bits: u8,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        // SLEEP is set out of reset: the internal oscillator is off until
+        // `enable()` clears it. ALLCALL is also set out of reset.
+        Config {
+            bits: mode1::AUTO_INCREMENT | mode1::SLEEP | mode1::ALL_CALL,
+        }
+    }
+}
+
+impl Config {
+    pub(crate) fn with_high(self, mask: u8) -> Self {
+        Config {
+            bits: self.bits | mask,
+        }
+    }
+
+    pub(crate) fn with_low(self, mask: u8) -> Self {
+        Config {
+            bits: self.bits & !mask,
+        }
+    }
+
+    pub(crate) fn is_high(self, mask: u8) -> bool {
+        (self.bits & mask) != 0
+    }
+}
@@ -0,0 +1,183 @@
+use crate::config::{mode1, mode2};
+use embedded_hal::blocking::i2c::Write;
+use crate::types::{Channel, Error, OutputLogicState, Pca9685};
+
+/// Internal oscillator frequency in Hz.
+const OSCILLATOR_FREQ_HZ: u32 = 25_000_000;
+
+pub(crate) mod reg {
+    pub(crate) const MODE1: u8 = 0x00;
+    pub(crate) const MODE2: u8 = 0x01;
+    pub(crate) const SUBADR1: u8 = 0x02;
+    pub(crate) const SUBADR2: u8 = 0x03;
+    pub(crate) const SUBADR3: u8 = 0x04;
+    pub(crate) const ALLCALLADR: u8 = 0x05;
+    pub(crate) const LED0_ON_L: u8 = 0x06;
+    pub(crate) const ALL_LED_ON_L: u8 = 0xFA;
+    pub(crate) const PRE_SCALE: u8 = 0xFE;
+}
+
+/// Special bit (bit 4 of the high byte) marking a channel as fully on/off,
+/// overriding the 12-bit count in the rest of the register pair.
+pub(crate) const FULL_ON_OFF_BIT: u8 = 0b0001_0000;
+
+impl Channel {
+    /// Offset of this channel's ON_L register relative to `LED0_ON_L`, or
+    /// `None` for `Channel::All`, which is addressed through the dedicated
+    /// `ALL_LED_*` registers instead.
+    pub(crate) fn register_offset(self) -> Option<u8> {
+        match self {
+            Channel::C0 => Some(0),
+            Channel::C1 => Some(1),
+            Channel::C2 => Some(2),
+            Channel::C3 => Some(3),
+            Channel::C4 => Some(4),
+            Channel::C5 => Some(5),
+            Channel::C6 => Some(6),
+            Channel::C7 => Some(7),
+            Channel::C8 => Some(8),
+            Channel::C9 => Some(9),
+            Channel::C10 => Some(10),
+            Channel::C11 => Some(11),
+            Channel::C12 => Some(12),
+            Channel::C13 => Some(13),
+            Channel::C14 => Some(14),
+            Channel::C15 => Some(15),
+            Channel::All => None,
+        }
+    }
+}
+
+impl<I2C> Pca9685<I2C> {
+    /// Register holding the ON_L byte for `channel`'s count pair.
+    ///
+    /// Shared between the blocking and async (`asynch.rs`) method sets,
+    /// which target different I²C trait bounds but the same register map.
+    pub(crate) fn on_off_registers(&self, channel: Channel) -> u8 {
+        match channel.register_offset() {
+            Some(offset) => reg::LED0_ON_L + 4 * offset,
+            None => reg::ALL_LED_ON_L,
+        }
+    }
+}
+
+impl<I2C, E> Pca9685<I2C>
+where
+    I2C: Write<Error = E>,
+{
+    pub(crate) fn write_register(&mut self, register: u8, value: u8) -> Result<(), Error<E>> {
+        let address = self.inner.borrow().address;
+        self.inner
+            .borrow_mut()
+            .i2c
+            .write(address, &[register, value])
+            .map_err(Error::I2C)
+    }
+
+    pub(crate) fn write_double_register(
+        &mut self,
+        register: u8,
+        value: u16,
+    ) -> Result<(), Error<E>> {
+        let address = self.inner.borrow().address;
+        self.write_double_register_to_address(address, register, value)
+    }
+
+    /// Like `write_double_register()`, but targets an arbitrary I²C address
+    /// rather than the device's own configured address. Used to broadcast
+    /// updates to the ALLCALL or a sub-address shared by several chips.
+    pub(crate) fn write_double_register_to_address(
+        &mut self,
+        address: u8,
+        register: u8,
+        value: u16,
+    ) -> Result<(), Error<E>> {
+        self.inner
+            .borrow_mut()
+            .i2c
+            .write(address, &[register, value as u8, (value >> 8) as u8])
+            .map_err(Error::I2C)
+    }
+
+    /// Enables the device.
+    pub fn enable(&mut self) -> Result<(), Error<E>> {
+        let config = self.inner.borrow().config.with_low(mode1::SLEEP);
+        self.write_register(reg::MODE1, config.bits)?;
+        self.inner.borrow_mut().config = config;
+        Ok(())
+    }
+
+    /// Disables the device (sets it to low power mode).
+    pub fn disable(&mut self) -> Result<(), Error<E>> {
+        let config = self.inner.borrow().config.with_high(mode1::SLEEP);
+        self.write_register(reg::MODE1, config.bits)?;
+        self.inner.borrow_mut().config = config;
+        Ok(())
+    }
+
+    /// Sets the output logic state.
+    ///
+    /// This allows for inverting the output logic if an external driver is
+    /// not used. See `OutputLogicState` for details.
+    pub fn set_output_logic_state(&mut self, state: OutputLogicState) -> Result<(), Error<E>> {
+        let mut value = mode2::OUTDRV;
+        if state == OutputLogicState::Inverted {
+            value |= mode2::INVRT;
+        }
+        self.write_register(reg::MODE2, value)
+    }
+
+    /// Sets the prescale value.
+    ///
+    /// The device must be disabled (see `disable()`) before calling this
+    /// method, since PRE_SCALE can only be written while the device is
+    /// asleep. The prescale value can be calculated for an update rate in
+    /// `Hz` with: `prescale_value = round(25MHz / (4096 * update_rate)) - 1`.
+    pub fn set_prescale(&mut self, prescale: u8) -> Result<(), Error<E>> {
+        if !self.inner.borrow().config.is_high(mode1::SLEEP) {
+            return Err(Error::InvalidInputData);
+        }
+        self.write_register(reg::PRE_SCALE, prescale)?;
+        self.inner.borrow_mut().prescale = prescale;
+        Ok(())
+    }
+
+    /// Returns the PWM update rate in Hz implied by the currently configured
+    /// prescale value.
+    pub(crate) fn frequency_hz(&self) -> u32 {
+        let prescale = self.inner.borrow().prescale;
+        OSCILLATOR_FREQ_HZ / (4096 * (u32::from(prescale) + 1))
+    }
+
+    /// Sets a channel to be always on.
+    pub fn set_channel_full_on(&mut self, channel: Channel, value: u16) -> Result<(), Error<E>> {
+        if value > 4095 {
+            return Err(Error::InvalidInputData);
+        }
+        let register = self.on_off_registers(channel);
+        self.write_double_register(register, value | u16::from(FULL_ON_OFF_BIT) << 8)?;
+        self.write_double_register(register + 2, 0)
+    }
+
+    /// Sets a channel to be always off.
+    pub fn set_channel_full_off(&mut self, channel: Channel) -> Result<(), Error<E>> {
+        let register = self.on_off_registers(channel);
+        self.write_double_register(register, 0)?;
+        self.write_double_register(register + 2, u16::from(FULL_ON_OFF_BIT) << 8)
+    }
+
+    /// Sets a channel's ON and OFF counts (12-bit values, `0..=4095`).
+    pub fn set_channel_on_off(
+        &mut self,
+        channel: Channel,
+        on: u16,
+        off: u16,
+    ) -> Result<(), Error<E>> {
+        if on > 4095 || off > 4095 {
+            return Err(Error::InvalidInputData);
+        }
+        let register = self.on_off_registers(channel);
+        self.write_double_register(register, on)?;
+        self.write_double_register(register + 2, off)
+    }
+}
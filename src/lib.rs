@@ -0,0 +1,52 @@
+//! This is a platform agnostic Rust driver for the PCA9685 PWM/Servo/LED
+//! controller, based on the [`embedded-hal`] traits.
+//!
+//! This driver allows you to:
+//! - Enable/disable the device. See: [`enable()`].
+//! - Set the output logic state. See: [`set_output_logic_state()`].
+//! - Set one or all channels output ON/OFF counts. See: [`set_channel_on_off()`].
+//! - Set the prescale value. See: [`set_prescale()`].
+//! - Drive a channel by pulse width or servo angle. See: [`set_channel_angle()`].
+//! - Stagger several channels' switching edges across the frame. See:
+//!   [`set_channels_phase_shifted()`].
+//! - Split the device into 16 independent channel handles. See: [`split()`].
+//! - Configure ALLCALL/sub-addresses for daisy-chained group updates. See:
+//!   [`enable_all_call()`].
+//! - Address a chain of devices by a flat logical-output index. See:
+//!   [`Pca9685Array`].
+//! - Drive the device without blocking, behind the `async` feature.
+//!
+//! [`embedded-hal`]: https://github.com/rust-embedded/embedded-hal
+//! [`enable()`]: struct.Pca9685.html#method.enable
+//! [`set_output_logic_state()`]: struct.Pca9685.html#method.set_output_logic_state
+//! [`set_channel_on_off()`]: struct.Pca9685.html#method.set_channel_on_off
+//! [`set_prescale()`]: struct.Pca9685.html#method.set_prescale
+//! [`set_channel_angle()`]: struct.Pca9685.html#method.set_channel_angle
+//! [`set_channels_phase_shifted()`]: struct.Pca9685.html#method.set_channels_phase_shifted
+//! [`split()`]: struct.Pca9685.html#method.split
+//! [`enable_all_call()`]: struct.Pca9685.html#method.enable_all_call
+//! [`Pca9685Array`]: struct.Pca9685Array.html
+#![deny(unsafe_code)]
+#![deny(missing_docs)]
+#![no_std]
+
+extern crate alloc;
+
+mod address;
+#[cfg(feature = "async")]
+mod asynch;
+mod chain;
+mod channels;
+mod config;
+mod device;
+mod phase_shift;
+mod servo;
+mod types;
+
+pub use address::SubAddress;
+pub use chain::Pca9685Array;
+pub use channels::{Parts, PwmChannel};
+pub use servo::ServoConfig;
+pub use types::{Channel, Error, OutputLogicState, Pca9685, SlaveAddr};
+
+const DEVICE_BASE_ADDRESS: u8 = 0b100_0000;
@@ -0,0 +1,185 @@
+use crate::device::reg;
+use embedded_hal::blocking::i2c::Write;
+use crate::types::{Channel, Error, Pca9685};
+
+impl<I2C, E> Pca9685<I2C>
+where
+    I2C: Write<Error = E>,
+{
+    /// Sets several channels' duty cycles while staggering their rising
+    /// (ON) edges across the 4096-step frame, to avoid switching every
+    /// output simultaneously.
+    ///
+    /// For the `i`-th of `n` channels passed in, with duty cycle `d`, the ON
+    /// delay is `on_i = (i * 4096 / n) % 4096` and the OFF delay is
+    /// `off_i = (on_i + d) % 4096`, wrapping around the end of the frame when
+    /// necessary. `d == 4096` selects full-on and `d == 0` selects full-off,
+    /// using the channel's dedicated full-on/off bit instead of a count.
+    ///
+    /// Returns `Error::InvalidInputData` if any duty cycle exceeds `4096`.
+    pub fn set_channels_phase_shifted(
+        &mut self,
+        channels: &[(Channel, u16)],
+    ) -> Result<(), Error<E>> {
+        let n = channels.len() as u32;
+        for (i, &(channel, duty)) in channels.iter().enumerate() {
+            if duty > 4096 {
+                return Err(Error::InvalidInputData);
+            }
+            let on = ((i as u32) * 4096 / n) as u16;
+            self.set_channel_phase_shifted(channel, on, duty)?;
+        }
+        Ok(())
+    }
+
+    /// Equivalent to `set_channels_phase_shifted()` for every output
+    /// channel (`C0..C15`), each driven with the same duty cycle.
+    pub fn set_all_channels_phase_shifted(&mut self, duty: u16) -> Result<(), Error<E>> {
+        let channels = [
+            (Channel::C0, duty),
+            (Channel::C1, duty),
+            (Channel::C2, duty),
+            (Channel::C3, duty),
+            (Channel::C4, duty),
+            (Channel::C5, duty),
+            (Channel::C6, duty),
+            (Channel::C7, duty),
+            (Channel::C8, duty),
+            (Channel::C9, duty),
+            (Channel::C10, duty),
+            (Channel::C11, duty),
+            (Channel::C12, duty),
+            (Channel::C13, duty),
+            (Channel::C14, duty),
+            (Channel::C15, duty),
+        ];
+        self.set_channels_phase_shifted(&channels)
+    }
+
+    /// Writes a single channel's ON/OFF registers for the phase-shifted
+    /// case, handling the full-on/full-off special bits and OFF-edge
+    /// wrap-around.
+    fn set_channel_phase_shifted(
+        &mut self,
+        channel: Channel,
+        on: u16,
+        duty: u16,
+    ) -> Result<(), Error<E>> {
+        if duty == 4096 {
+            return self.set_channel_full_on(channel, 0);
+        }
+        if duty == 0 {
+            return self.set_channel_full_off(channel);
+        }
+        // The OFF count already wraps modulo 4096 when the edge crosses the
+        // end of the frame; no extra bit is needed for that case.
+        let off = (on + duty) % 4096;
+        let register = match channel.register_offset() {
+            Some(offset) => reg::LED0_ON_L + 4 * offset,
+            None => reg::ALL_LED_ON_L,
+        };
+        self.write_double_register(register, on)?;
+        self.write_double_register(register + 2, off)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device::FULL_ON_OFF_BIT;
+
+    /// A minimal `Write` mock recording up to 16 writes, avoiding any need
+    /// for an allocator under `no_std`.
+    #[derive(Default)]
+    struct RecordingI2c {
+        writes: [(u8, [u8; 3]); 16],
+        count: usize,
+    }
+
+    impl Write for RecordingI2c {
+        type Error = ();
+
+        fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), ()> {
+            let mut buf = [0u8; 3];
+            buf[..bytes.len()].copy_from_slice(bytes);
+            self.writes[self.count] = (address, buf);
+            self.count += 1;
+            Ok(())
+        }
+    }
+
+    fn device() -> Pca9685<RecordingI2c> {
+        Pca9685::<RecordingI2c>::default()
+    }
+
+    #[test]
+    fn rejects_duty_above_4096() {
+        let mut dev = device();
+        assert!(matches!(
+            dev.set_channels_phase_shifted(&[(Channel::C0, 4097)]),
+            Err(Error::InvalidInputData)
+        ));
+    }
+
+    #[test]
+    fn staggers_on_edges_across_the_frame() {
+        let mut dev = device();
+        // 4 channels over a 4096-step frame: on_i == i * 1024. C0 and C1,
+        // checked here, do not wrap for this duty cycle.
+        dev.set_channels_phase_shifted(&[
+            (Channel::C0, 3000),
+            (Channel::C1, 3000),
+            (Channel::C2, 3000),
+            (Channel::C3, 3000),
+        ])
+        .unwrap();
+
+        let writes = dev.inner.borrow().i2c.writes;
+        // C0: on == 0, off == 3000 (no wrap).
+        assert_eq!(writes[0].1, [6, 0, 0]);
+        assert_eq!(writes[1].1, [8, 184, 11]);
+        // C1: on == 1024, off == 4024 (no wrap).
+        assert_eq!(writes[2].1, [10, 0, 4]);
+        assert_eq!(writes[3].1, [12, 184, 15]);
+    }
+
+    #[test]
+    fn off_edge_wraps_around_the_end_of_the_frame() {
+        let mut dev = device();
+        // C3: on == 3 * 4096 / 4 == 3072, duty == 3000 wraps the OFF edge
+        // to (3072 + 3000) % 4096 == 1976.
+        dev.set_channels_phase_shifted(&[
+            (Channel::C0, 3000),
+            (Channel::C1, 3000),
+            (Channel::C2, 3000),
+            (Channel::C3, 3000),
+        ])
+        .unwrap();
+
+        let writes = dev.inner.borrow().i2c.writes;
+        assert_eq!(writes[6].1, [18, 0, 12]); // LED3_ON_L, on == 3072
+        assert_eq!(writes[7].1, [20, 184, 7]); // LED3_OFF_L, off == 1976
+    }
+
+    #[test]
+    fn duty_4096_selects_full_on() {
+        let mut dev = device();
+        dev.set_channels_phase_shifted(&[(Channel::C0, 4096)])
+            .unwrap();
+
+        let writes = dev.inner.borrow().i2c.writes;
+        assert_eq!(writes[0].1, [6, 0, FULL_ON_OFF_BIT]);
+        assert_eq!(writes[1].1, [8, 0, 0]);
+    }
+
+    #[test]
+    fn duty_0_selects_full_off() {
+        let mut dev = device();
+        dev.set_channels_phase_shifted(&[(Channel::C0, 0)])
+            .unwrap();
+
+        let writes = dev.inner.borrow().i2c.writes;
+        assert_eq!(writes[0].1, [6, 0, 0]);
+        assert_eq!(writes[1].1, [8, 0, FULL_ON_OFF_BIT]);
+    }
+}
@@ -0,0 +1,188 @@
+use embedded_hal::blocking::i2c::Write;
+use crate::types::{Channel, Error, Pca9685};
+
+/// Configuration describing how a servo's pulse-width range maps onto its
+/// angular range.
+///
+/// `min_us` and `max_us` are the pulse widths (in microseconds) corresponding
+/// to `0` and `max_angle` degrees respectively.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ServoConfig {
+    /// Pulse width in microseconds corresponding to the `0` degree position.
+    pub min_us: u16,
+    /// Pulse width in microseconds corresponding to the `max_angle` position.
+    pub max_us: u16,
+    /// Maximum angle, in degrees, the servo can be driven to.
+    pub max_angle: f32,
+}
+
+impl Default for ServoConfig {
+    /// A typical hobby servo: 1-2ms pulses over a 180 degree range.
+    fn default() -> Self {
+        ServoConfig {
+            min_us: 1000,
+            max_us: 2000,
+            max_angle: 180.0,
+        }
+    }
+}
+
+impl<I2C, E> Pca9685<I2C>
+where
+    I2C: Write<Error = E>,
+{
+    /// Sets a channel's output to the given pulse width, in microseconds,
+    /// using the currently configured prescale value to convert it into an
+    /// ON/OFF count pair (`ON = 0`, `OFF = count`).
+    ///
+    /// Returns `Error::InvalidInputData` if `pulse_us` does not fit within
+    /// the current PWM period (`1_000_000 / frequency_hz()`).
+    pub fn set_channel_pulse_us(&mut self, channel: Channel, pulse_us: u16) -> Result<(), Error<E>> {
+        let count = self.pulse_us_to_count(pulse_us)?;
+        self.set_channel_on_off(channel, 0, count)
+    }
+
+    /// Drives a channel to the given angle, in degrees, according to the
+    /// provided `ServoConfig`.
+    ///
+    /// The angle is linearly mapped onto the `[min_us, max_us]` pulse range
+    /// and clamped to `[0, max_angle]` before conversion.
+    pub fn set_channel_angle(
+        &mut self,
+        channel: Channel,
+        angle: f32,
+        config: ServoConfig,
+    ) -> Result<(), Error<E>> {
+        let angle = angle.max(0.0).min(config.max_angle);
+        let span_us = f32::from(config.max_us - config.min_us);
+        let pulse_us = f32::from(config.min_us) + span_us * (angle / config.max_angle);
+        // `f32::round()` lives in `std`/`libm`, unavailable under `no_std`;
+        // `pulse_us` is never negative here, so truncating after adding
+        // `0.5` rounds to the nearest microsecond without it.
+        self.set_channel_pulse_us(channel, (pulse_us + 0.5) as u16)
+    }
+
+    fn pulse_us_to_count(&self, pulse_us: u16) -> Result<u16, Error<E>> {
+        let period_us = 1_000_000 / self.frequency_hz();
+        if u32::from(pulse_us) > period_us {
+            return Err(Error::InvalidInputData);
+        }
+        // `round(pulse_us * 4096 * freq_hz / 1_000_000)` in integer
+        // arithmetic: add half the divisor before truncating.
+        let numerator = u64::from(pulse_us) * 4096 * u64::from(self.frequency_hz());
+        let count = (numerator + 500_000) / 1_000_000;
+        Ok(count.min(4095) as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A minimal `Write` mock recording up to 4 writes, avoiding any need
+    /// for an allocator under `no_std`.
+    #[derive(Default)]
+    struct RecordingI2c {
+        writes: [(u8, [u8; 3]); 4],
+        count: usize,
+    }
+
+    impl Write for RecordingI2c {
+        type Error = ();
+
+        fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), ()> {
+            let mut buf = [0u8; 3];
+            buf[..bytes.len()].copy_from_slice(bytes);
+            self.writes[self.count] = (address, buf);
+            self.count += 1;
+            Ok(())
+        }
+    }
+
+    fn device() -> Pca9685<RecordingI2c> {
+        let dev = Pca9685::<RecordingI2c>::default();
+        // frequency_hz() == 23 Hz for prescale == 255.
+        dev.inner.borrow_mut().prescale = 255;
+        dev
+    }
+
+    #[test]
+    fn pulse_us_to_count_rounds_to_nearest() {
+        let dev = device();
+        assert_eq!(dev.pulse_us_to_count(1500).unwrap(), 141);
+    }
+
+    #[test]
+    fn pulse_us_to_count_accepts_period_boundary() {
+        let dev = device();
+        let period_us = 1_000_000 / dev.frequency_hz();
+        assert!(dev.pulse_us_to_count(period_us as u16).is_ok());
+    }
+
+    #[test]
+    fn pulse_us_to_count_rejects_beyond_period() {
+        let dev = device();
+        let period_us = 1_000_000 / dev.frequency_hz();
+        assert!(matches!(
+            dev.pulse_us_to_count(period_us as u16 + 1),
+            Err(Error::InvalidInputData)
+        ));
+    }
+
+    #[test]
+    fn set_channel_pulse_us_writes_on_and_off_registers() {
+        let mut dev = device();
+        let address = dev.inner.borrow().address;
+        dev.set_channel_pulse_us(Channel::C0, 1500).unwrap();
+
+        let inner = dev.inner.borrow();
+        assert_eq!(inner.i2c.count, 2);
+        assert_eq!(inner.i2c.writes[0], (address, [6, 0, 0]));
+        assert_eq!(inner.i2c.writes[1], (address, [8, 141, 0]));
+    }
+
+    #[test]
+    fn set_channel_angle_matches_equivalent_pulse_width() {
+        let mut by_angle = device();
+        by_angle
+            .set_channel_angle(Channel::C0, 90.0, ServoConfig::default())
+            .unwrap();
+
+        let mut by_pulse = device();
+        by_pulse.set_channel_pulse_us(Channel::C0, 1500).unwrap();
+
+        assert_eq!(
+            by_angle.inner.borrow().i2c.writes,
+            by_pulse.inner.borrow().i2c.writes
+        );
+    }
+
+    #[test]
+    fn set_channel_angle_clamps_to_servo_config_range() {
+        let mut below = device();
+        below
+            .set_channel_angle(Channel::C0, -10.0, ServoConfig::default())
+            .unwrap();
+        let mut at_zero = device();
+        at_zero
+            .set_channel_angle(Channel::C0, 0.0, ServoConfig::default())
+            .unwrap();
+        assert_eq!(
+            below.inner.borrow().i2c.writes,
+            at_zero.inner.borrow().i2c.writes
+        );
+
+        let mut above = device();
+        above
+            .set_channel_angle(Channel::C0, 200.0, ServoConfig::default())
+            .unwrap();
+        let mut at_max = device();
+        at_max
+            .set_channel_angle(Channel::C0, 180.0, ServoConfig::default())
+            .unwrap();
+        assert_eq!(
+            above.inner.borrow().i2c.writes,
+            at_max.inner.borrow().i2c.writes
+        );
+    }
+}
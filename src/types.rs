@@ -1,14 +1,55 @@
-use config::Config;
+use alloc::rc::Rc;
+use crate::config::Config;
+use core::cell::RefCell;
+use crate::DEVICE_BASE_ADDRESS;
 
-/// PCA9685 PWM/Servo/LED controller.
-#[derive(Debug, Default)]
-pub struct Pca9685<I2C> {
+/// Mutable device state, shared between a `Pca9685` and any `PwmChannel`s
+/// split from it.
+#[derive(Debug)]
+pub(crate) struct Inner<I2C> {
     /// The concrete I²C device implementation.
     pub(crate) i2c: I2C,
     /// The I²C device address.
     pub(crate) address: u8,
     /// Current device configuration.
     pub(crate) config: Config,
+    /// Currently configured PRE_SCALE register value.
+    ///
+    /// Defaults to `0x1E`, matching the device's power-on reset value
+    /// (roughly 200 Hz with the internal 25 MHz oscillator).
+    pub(crate) prescale: u8,
+}
+
+/// PCA9685 PWM/Servo/LED controller.
+///
+/// Cheaply `Clone`-able: a clone shares the same underlying device state (an
+/// `Rc<RefCell<_>>`) rather than addressing a second device. `split()` uses
+/// this to hand out independent `PwmChannel`s that each still talk to the
+/// one physical device.
+#[derive(Debug)]
+pub struct Pca9685<I2C> {
+    pub(crate) inner: Rc<RefCell<Inner<I2C>>>,
+}
+
+impl<I2C> Clone for Pca9685<I2C> {
+    fn clone(&self) -> Self {
+        Pca9685 {
+            inner: Rc::clone(&self.inner),
+        }
+    }
+}
+
+impl<I2C: Default> Default for Pca9685<I2C> {
+    fn default() -> Self {
+        Pca9685 {
+            inner: Rc::new(RefCell::new(Inner {
+                i2c: I2C::default(),
+                address: DEVICE_BASE_ADDRESS,
+                config: Config::default(),
+                prescale: 0x1E,
+            })),
+        }
+    }
 }
 
 /// All possible errors in this crate
@@ -108,7 +149,7 @@ impl SlaveAddr {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use DEVICE_BASE_ADDRESS as DEV_ADDR;
+    use crate::DEVICE_BASE_ADDRESS as DEV_ADDR;
 
     #[test]
     fn can_get_default_address() {